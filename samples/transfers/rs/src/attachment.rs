@@ -0,0 +1,81 @@
+use crate::error::FastBoundError;
+
+/// A supporting document (4473 scan, invoice, shipping label, ...) attached
+/// to a transfer submission.
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// MIME types FastBound accepts for transfer attachments.
+const ALLOWED_CONTENT_TYPES: &[&str] = &["application/pdf", "image/jpeg", "image/png", "image/tiff"];
+
+impl Attachment {
+    pub fn new(filename: impl Into<String>, content_type: impl Into<String>, bytes: Vec<u8>) -> Self {
+        Self {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            bytes,
+        }
+    }
+
+    pub fn is_supported_content_type(&self) -> bool {
+        ALLOWED_CONTENT_TYPES.contains(&self.content_type.as_str())
+    }
+}
+
+/// Rejects unsupported MIME types and an over-limit combined size before a
+/// client ever builds the multipart request.
+pub(crate) fn validate(attachments: &[Attachment], max_total: u64) -> Result<(), FastBoundError> {
+    for attachment in attachments {
+        if !attachment.is_supported_content_type() {
+            return Err(FastBoundError::UnsupportedAttachmentType {
+                content_type: attachment.content_type.clone(),
+            });
+        }
+    }
+
+    let total: u64 = attachments.iter().map(|a| a.bytes.len() as u64).sum();
+    if total > max_total {
+        return Err(FastBoundError::AttachmentsTooLarge { total, max: max_total });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(content_type: &str, size: usize) -> Attachment {
+        Attachment::new("file", content_type, vec![0u8; size])
+    }
+
+    #[test]
+    fn validate_accepts_supported_types_within_the_limit() {
+        let attachments = vec![attachment("application/pdf", 10), attachment("image/png", 10)];
+        assert!(validate(&attachments, 100).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unsupported_content_type() {
+        let attachments = vec![attachment("application/x-msdownload", 10)];
+        let err = validate(&attachments, 100).unwrap_err();
+        assert!(matches!(err, FastBoundError::UnsupportedAttachmentType { .. }));
+    }
+
+    #[test]
+    fn validate_rejects_combined_size_over_the_limit() {
+        let attachments = vec![attachment("application/pdf", 60), attachment("image/png", 60)];
+        let err = validate(&attachments, 100).unwrap_err();
+        assert!(matches!(err, FastBoundError::AttachmentsTooLarge { total: 120, max: 100 }));
+    }
+
+    #[test]
+    fn validate_allows_exactly_the_limit() {
+        let attachments = vec![attachment("application/pdf", 100)];
+        assert!(validate(&attachments, 100).is_ok());
+    }
+}
@@ -0,0 +1,217 @@
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+
+use crate::attachment::{self, Attachment};
+use crate::auth::Auth;
+use crate::error::FastBoundError;
+use crate::payload::TransferPayload;
+use crate::response::{TransferError, TransferResponse};
+use crate::retry::RetryPolicy;
+
+/// Default cap on the combined size of a transfer's attachments (25 MiB).
+const DEFAULT_MAX_ATTACHMENTS_SIZE: u64 = 25 * 1024 * 1024;
+
+/// A client for FastBound's transfers API.
+///
+/// Owns a reusable `reqwest::Client`, the base URL, and an `Auth` mode, so
+/// callers construct one `FastBoundClient` and reuse it across submissions.
+pub struct FastBoundClient {
+    base_url: String,
+    auth: Auth,
+    http: reqwest::Client,
+    retry_policy: RetryPolicy,
+    max_attachments_size: u64,
+}
+
+impl FastBoundClient {
+    /// Constructs a client using HTTP Basic auth. Use `with_auth` for the
+    /// HMAC-signed mode.
+    pub fn new(base_url: impl Into<String>, username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::with_auth(base_url, Auth::basic(username, password))
+    }
+
+    pub fn with_auth(base_url: impl Into<String>, auth: Auth) -> Self {
+        Self {
+            base_url: base_url.into(),
+            auth,
+            http: reqwest::Client::new(),
+            retry_policy: RetryPolicy::default(),
+            max_attachments_size: DEFAULT_MAX_ATTACHMENTS_SIZE,
+        }
+    }
+
+    /// Overrides the default retry policy, e.g. to disable retries with
+    /// `RetryPolicy::none()` or tune `max_retries`/backoff bounds.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Overrides the default cap on combined attachment size.
+    pub fn with_max_attachments_size(mut self, max_attachments_size: u64) -> Self {
+        self.max_attachments_size = max_attachments_size;
+        self
+    }
+
+    fn auth_headers(&self, method: &str, body: &[u8]) -> Result<reqwest::header::HeaderMap, FastBoundError> {
+        let path = reqwest::Url::parse(&self.base_url)
+            .map(|url| url.path().to_string())
+            .unwrap_or_default();
+        self.auth.headers(method, &path, body)
+    }
+
+    /// Submits a transfer, retrying transient failures per `retry_policy`
+    /// with exponential backoff and full jitter. Every attempt re-POSTs the
+    /// exact same payload and idempotency key, so FastBound de-duplicates
+    /// the transfer rather than creating a second one.
+    pub async fn submit_transfer(
+        &self,
+        payload: &TransferPayload,
+    ) -> Result<TransferResponse, FastBoundError> {
+        let mut attempt = 0;
+        loop {
+            match self.submit_transfer_once(payload).await {
+                Ok(response) => return Ok(response),
+                Err(err) if attempt < self.retry_policy.max_retries && err.is_retryable() => {
+                    let backoff = self.retry_policy.backoff(attempt);
+                    let delay = match err.retry_after() {
+                        Some(retry_after) => backoff.max(retry_after),
+                        None => backoff,
+                    };
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Makes a single submission attempt, parsing FastBound's JSON response
+    /// and branching on status code so callers get a concrete error variant
+    /// instead of a raw body to inspect themselves.
+    async fn submit_transfer_once(
+        &self,
+        payload: &TransferPayload,
+    ) -> Result<TransferResponse, FastBoundError> {
+        let body = serde_json::to_vec(payload)?;
+        let mut headers = self.auth_headers("POST", &body)?;
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let response = self
+            .http
+            .post(&self.base_url)
+            .headers(headers)
+            .body(body)
+            .send()
+            .await?;
+
+        Self::handle_response(response).await
+    }
+
+    /// Submits a transfer together with supporting documents (4473 scans,
+    /// invoices, shipping labels) as `multipart/form-data`: one part carries
+    /// the JSON payload, and one part per attachment carries its filename,
+    /// content type, and raw bytes. Retries the same way `submit_transfer`
+    /// does.
+    pub async fn submit_transfer_with_attachments(
+        &self,
+        payload: &TransferPayload,
+        attachments: &[Attachment],
+    ) -> Result<TransferResponse, FastBoundError> {
+        if matches!(self.auth, Auth::HmacSigned { .. }) {
+            return Err(FastBoundError::HmacUnsupportedForAttachments);
+        }
+
+        attachment::validate(attachments, self.max_attachments_size)?;
+
+        let mut attempt = 0;
+        loop {
+            match self
+                .submit_transfer_with_attachments_once(payload, attachments)
+                .await
+            {
+                Ok(response) => return Ok(response),
+                Err(err) if attempt < self.retry_policy.max_retries && err.is_retryable() => {
+                    let backoff = self.retry_policy.backoff(attempt);
+                    let delay = match err.retry_after() {
+                        Some(retry_after) => backoff.max(retry_after),
+                        None => backoff,
+                    };
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    async fn submit_transfer_with_attachments_once(
+        &self,
+        payload: &TransferPayload,
+        attachments: &[Attachment],
+    ) -> Result<TransferResponse, FastBoundError> {
+        let json = serde_json::to_string(payload)?;
+        let headers = self.auth_headers("POST", json.as_bytes())?;
+
+        let mut form = reqwest::multipart::Form::new().text("transfer", json);
+        for attachment in attachments {
+            let part = reqwest::multipart::Part::bytes(attachment.bytes.clone())
+                .file_name(attachment.filename.clone())
+                .mime_str(&attachment.content_type)?;
+            form = form.part("attachments[]", part);
+        }
+
+        let response = self
+            .http
+            .post(&self.base_url)
+            .headers(headers)
+            .multipart(form)
+            .send()
+            .await?;
+
+        Self::handle_response(response).await
+    }
+
+    async fn handle_response(response: reqwest::Response) -> Result<TransferResponse, FastBoundError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json::<TransferResponse>().await?);
+        }
+
+        match status {
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                let body = response.text().await.unwrap_or_default();
+                Err(FastBoundError::Authentication { status, body })
+            }
+            reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+                let body = response.text().await.unwrap_or_default();
+                match serde_json::from_str::<TransferError>(&body) {
+                    Ok(transfer_error) => Err(FastBoundError::Validation(transfer_error)),
+                    Err(_) => Err(FastBoundError::Api { status, body, retry_after: None }),
+                }
+            }
+            reqwest::StatusCode::CONFLICT => {
+                let body = response.text().await.unwrap_or_default();
+                Err(FastBoundError::IdempotencyConflict { body })
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                let retry_after = Self::retry_after_header(&response);
+                Err(FastBoundError::RateLimited { retry_after })
+            }
+            _ => {
+                let retry_after = Self::retry_after_header(&response);
+                let body = response.text().await.unwrap_or_default();
+                Err(FastBoundError::Api { status, body, retry_after })
+            }
+        }
+    }
+
+    /// Parses the `Retry-After` header (in seconds) if the response sent
+    /// one, so 429s and retryable 5xxs both honor it.
+    fn retry_after_header(response: &reqwest::Response) -> Option<u64> {
+        response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::response::TransferError;
+
+/// Failure modes for a `FastBoundClient` request.
+#[derive(Debug)]
+pub enum FastBoundError {
+    /// The request never got a response (connection, timeout, TLS, etc.),
+    /// or a successful response's body couldn't be read or decoded.
+    Transport(reqwest::Error),
+    /// 401/403 — the configured credentials were rejected.
+    Authentication { status: reqwest::StatusCode, body: String },
+    /// 422 — the payload failed server-side validation.
+    Validation(TransferError),
+    /// 409 — a different payload was already submitted under this
+    /// idempotency key.
+    IdempotencyConflict { body: String },
+    /// 429 — too many requests; `retry_after` holds the server's
+    /// `Retry-After` value in seconds, if it sent one.
+    RateLimited { retry_after: Option<u64> },
+    /// Any other non-success status the client doesn't special-case,
+    /// including the 500/502/503/504 retryable ones. `retry_after` holds
+    /// the server's `Retry-After` value in seconds, if it sent one.
+    Api {
+        status: reqwest::StatusCode,
+        body: String,
+        retry_after: Option<u64>,
+    },
+    /// An attachment's declared content type isn't one FastBound accepts.
+    UnsupportedAttachmentType { content_type: String },
+    /// The combined size of the transfer's attachments exceeds the
+    /// client's configured `max_attachments_size`.
+    AttachmentsTooLarge { total: u64, max: u64 },
+    /// `Auth::HmacSigned` was configured for a multipart attachment
+    /// submission. The signature is computed over the canonical request
+    /// body, but the bytes actually sent are the encoded multipart form
+    /// (boundaries, per-part headers, attachment bytes) built from it, so
+    /// the server would never be able to verify it. Use `Auth::Basic` for
+    /// transfers with attachments, or submit the transfer without
+    /// attachments and attach documents separately.
+    HmacUnsupportedForAttachments,
+}
+
+impl fmt::Display for FastBoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FastBoundError::Transport(err) => write!(f, "request failed: {}", err),
+            FastBoundError::Authentication { status, body } => {
+                write!(f, "authentication failed ({}): {}", status, body)
+            }
+            FastBoundError::Validation(err) => write!(f, "validation failed: {}", err.message),
+            FastBoundError::IdempotencyConflict { body } => {
+                write!(f, "idempotency conflict: {}", body)
+            }
+            FastBoundError::RateLimited { retry_after } => match retry_after {
+                Some(seconds) => write!(f, "rate limited, retry after {}s", seconds),
+                None => write!(f, "rate limited"),
+            },
+            FastBoundError::Api { status, body, .. } => {
+                write!(f, "FastBound API returned {}: {}", status, body)
+            }
+            FastBoundError::UnsupportedAttachmentType { content_type } => {
+                write!(f, "unsupported attachment content type: {}", content_type)
+            }
+            FastBoundError::AttachmentsTooLarge { total, max } => {
+                write!(f, "attachments total {} bytes, exceeding the {} byte limit", total, max)
+            }
+            FastBoundError::HmacUnsupportedForAttachments => write!(
+                f,
+                "Auth::HmacSigned can't sign multipart attachment submissions; use Auth::Basic for transfers with attachments"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FastBoundError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FastBoundError::Transport(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FastBoundError {
+    fn from(err: reqwest::Error) -> Self {
+        FastBoundError::Transport(err)
+    }
+}
+
+impl From<serde_json::Error> for FastBoundError {
+    fn from(err: serde_json::Error) -> Self {
+        FastBoundError::Api {
+            status: reqwest::StatusCode::BAD_REQUEST,
+            body: format!("failed to serialize transfer payload: {}", err),
+            retry_after: None,
+        }
+    }
+}
+
+impl FastBoundError {
+    /// Whether this failure is transient and safe to retry by re-POSTing
+    /// the same payload and idempotency key. 4xx validation/auth/conflict
+    /// failures are not, since retrying them fails the same way again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            FastBoundError::Transport(_) | FastBoundError::RateLimited { .. } => true,
+            FastBoundError::Api { status, .. } => crate::retry::is_retryable_status(*status),
+            FastBoundError::Authentication { .. }
+            | FastBoundError::Validation(_)
+            | FastBoundError::IdempotencyConflict { .. }
+            | FastBoundError::UnsupportedAttachmentType { .. }
+            | FastBoundError::AttachmentsTooLarge { .. }
+            | FastBoundError::HmacUnsupportedForAttachments => false,
+        }
+    }
+
+    /// The minimum time to wait before retrying, if the server told us one
+    /// via `Retry-After`.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            FastBoundError::RateLimited { retry_after: Some(seconds) }
+            | FastBoundError::Api { retry_after: Some(seconds), .. } => {
+                Some(std::time::Duration::from_secs(*seconds))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! A client library for FastBound's transfers API.
+
+mod attachment;
+mod auth;
+mod client;
+mod error;
+mod payload;
+mod response;
+mod retry;
+
+pub use attachment::Attachment;
+pub use auth::Auth;
+pub use client::FastBoundClient;
+pub use error::FastBoundError;
+pub use payload::{generate_idempotency_key, Item, TransferBuilder, TransferPayload};
+pub use response::{FieldError, TransferError, TransferResponse};
+pub use retry::RetryPolicy;
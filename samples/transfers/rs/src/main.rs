@@ -1,109 +1,25 @@
-use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
-use chrono::Utc;
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
-use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use std::error::Error;
+use std::time::Duration;
 
-const USERNAME: &str = "YOUR_USERNAME";
-const PASSWORD: &str = "YOUR_PASSWORD";
-const API_URL: &str = "https://cloud.fastbound.com/api/transfers";
-
-#[derive(Debug, Serialize, Deserialize)]
-struct Item {
-    manufacturer: String,
-    importer: Option<String>,
-    country: String,
-    model: String,
-    caliber: String,
-    #[serde(rename = "type")]
-    item_type: String,
-    serial: String,
-    sku: String,
-    mpn: String,
-    upc: String,
-    barrel_length: f64,
-    overall_length: f64,
-    cost: f64,
-    price: f64,
-    condition: String,
-    note: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct TransferPayload {
-    #[serde(rename = "$schema")]
-    schema: String,
-    idempotency_key: String,
-    transferor: String,
-    transferee: String,
-    transferee_emails: Vec<String>,
-    tracking_number: String,
-    po_number: String,
-    invoice_number: String,
-    acquire_type: String,
-    note: String,
-    items: Vec<Item>,
-}
-
-fn generate_idempotency_key(
-    shipment_date: &str,
-    transferor: &str,
-    transferee: &str,
-    tracking_number: &str,
-    po_number: &str,
-    invoice_number: &str,
-    serial_numbers: &[String],
-) -> String {
-    let data = format!(
-        "{}\n{}\n{}\n{}\n{}\n{}\n{}",
-        shipment_date,
-        transferor,
-        transferee,
-        tracking_number,
-        po_number,
-        invoice_number,
-        serial_numbers.join("\n")
-    );
-
-    let mut hasher = Sha256::new();
-    hasher.update(data.as_bytes());
-    format!("{:x}", hasher.finalize())
-}
-
-async fn send_post_request(json_payload: &str) -> Result<(), Box<dyn Error>> {
-    let auth_string = BASE64.encode(format!("{}:{}", USERNAME, PASSWORD));
-    let mut headers = HeaderMap::new();
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Basic {}", auth_string))?,
-    );
-    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-
-    let client = reqwest::Client::new();
-    let response = client
-        .post(API_URL)
-        .headers(headers)
-        .body(json_payload.to_string())
-        .send()
-        .await?;
-
-    println!("HTTP Code: {}", response.status());
-    println!("Response: {}", response.text().await?);
-    Ok(())
-}
+use chrono::Utc;
+use fastbound_transfers::{Attachment, Auth, FastBoundClient, Item, RetryPolicy, TransferBuilder};
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let shipment_date = Utc::now().format("%Y-%m-%d").to_string();
-    let transferor = "1-23-456-78-9A-12345";
-    let transferee = "1-23-456-78-9B-54321";
-    let tracking_number = "1Z999AA10123456784";
-    let po_number = "PO123456";
-    let invoice_number = "INV98765";
+const API_URL: &str = "https://cloud.fastbound.com/api/transfers";
 
-    let items = vec![
-        Item {
+fn sample_payload(shipment_date: String) -> fastbound_transfers::TransferPayload {
+    TransferBuilder::new()
+        .shipment_date(shipment_date)
+        .transferor("1-23-456-78-9A-12345")
+        .transferee("1-23-456-78-9B-54321")
+        .transferee_email("transferee@example.com")
+        .transferee_email("transferee@example.net")
+        .transferee_email("transferee@example.org")
+        .tracking_number("1Z999AA10123456784")
+        .po_number("PO123456")
+        .invoice_number("INV98765")
+        .acquire_type("Purchase")
+        .note("This is a test transfer.")
+        .item(Item {
             manufacturer: "Glock".to_string(),
             importer: None,
             country: "Austria".to_string(),
@@ -120,8 +36,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
             price: 650.00,
             condition: "New".to_string(),
             note: "Brand new firearm".to_string(),
-        },
-        Item {
+        })
+        .item(Item {
             manufacturer: "Smith & Wesson".to_string(),
             importer: None,
             country: "USA".to_string(),
@@ -138,40 +54,44 @@ async fn main() -> Result<(), Box<dyn Error>> {
             price: 600.00,
             condition: "New".to_string(),
             note: "Compact pistol".to_string(),
-        },
-    ];
+        })
+        .build()
+}
 
-    let serial_numbers: Vec<String> = items.iter().map(|item| item.serial.clone()).collect();
-    let idempotency_key = generate_idempotency_key(
-        &shipment_date,
-        transferor,
-        transferee,
-        tracking_number,
-        po_number,
-        invoice_number,
-        &serial_numbers,
-    );
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let shipment_date = Utc::now().format("%Y-%m-%d").to_string();
+    let payload = sample_payload(shipment_date);
 
-    let payload = TransferPayload {
-        schema: "https://schemas.fastbound.org/transfers-push-v1.json".to_string(),
-        idempotency_key,
-        transferor: transferor.to_string(),
-        transferee: transferee.to_string(),
-        transferee_emails: vec![
-            "transferee@example.com".to_string(),
-            "transferee@example.net".to_string(),
-            "transferee@example.org".to_string(),
-        ],
-        tracking_number: tracking_number.to_string(),
-        po_number: po_number.to_string(),
-        invoice_number: invoice_number.to_string(),
-        acquire_type: "Purchase".to_string(),
-        note: "This is a test transfer.".to_string(),
-        items,
-    };
+    // A plain-JSON submission over HTTP Basic auth, with a custom retry
+    // policy (FastBound de-duplicates retried submissions via the payload's
+    // idempotency key).
+    let basic_client = FastBoundClient::new(API_URL, "YOUR_USERNAME", "YOUR_PASSWORD")
+        .with_retry_policy(RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+        });
+    let response = basic_client.submit_transfer(&payload).await?;
+    println!("Transfer {} submitted with status {}", response.id, response.status);
 
-    let json_payload = serde_json::to_string_pretty(&payload)?;
-    send_post_request(&json_payload).await?;
+    // The same transfer, with a scanned 4473 attached.
+    let attachments = vec![Attachment::new(
+        "4473.pdf",
+        "application/pdf",
+        std::fs::read("4473.pdf").unwrap_or_default(),
+    )];
+    let response = basic_client
+        .submit_transfer_with_attachments(&payload, &attachments)
+        .await?;
+    println!("Transfer {} (with attachment) submitted with status {}", response.id, response.status);
+
+    // HMAC-signed auth, for deployments that prefer signed requests over a
+    // reusable password on every call.
+    let hmac_client =
+        FastBoundClient::with_auth(API_URL, Auth::hmac_signed("YOUR_KEY_ID", "YOUR_HMAC_SECRET"));
+    let response = hmac_client.submit_transfer(&payload).await?;
+    println!("Transfer {} (HMAC-signed) submitted with status {}", response.id, response.status);
 
     Ok(())
-} 
\ No newline at end of file
+}
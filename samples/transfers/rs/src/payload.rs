@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Intended to mirror `transfers-push-v1.json`, which is assumed to expect
+/// camelCase field names; `type` is the only field that diverges from a
+/// mechanical camelCase conversion. We don't have a copy of the published
+/// schema in this repo to verify the casing against, so this is our best
+/// reading of FastBound's docs rather than a checked fact.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    pub manufacturer: String,
+    pub importer: Option<String>,
+    pub country: String,
+    pub model: String,
+    pub caliber: String,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub serial: String,
+    pub sku: String,
+    pub mpn: String,
+    pub upc: String,
+    pub barrel_length: f64,
+    pub overall_length: f64,
+    pub cost: f64,
+    pub price: f64,
+    pub condition: String,
+    pub note: String,
+}
+
+/// Intended to mirror `transfers-push-v1.json`, which is assumed to expect
+/// camelCase field names; `$schema` is the only field that diverges from a
+/// mechanical camelCase conversion. We don't have a copy of the published
+/// schema in this repo to verify the casing against, so this is our best
+/// reading of FastBound's docs rather than a checked fact.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferPayload {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub idempotency_key: String,
+    pub shipment_date: String,
+    pub transferor: String,
+    pub transferee: String,
+    pub transferee_emails: Vec<String>,
+    pub tracking_number: String,
+    pub po_number: String,
+    pub invoice_number: String,
+    pub acquire_type: String,
+    pub note: String,
+    pub items: Vec<Item>,
+}
+
+/// Computes a deterministic idempotency key from the shipment metadata and
+/// serial numbers, so retried submissions of the same transfer de-duplicate
+/// server-side.
+pub fn generate_idempotency_key(
+    shipment_date: &str,
+    transferor: &str,
+    transferee: &str,
+    tracking_number: &str,
+    po_number: &str,
+    invoice_number: &str,
+    serial_numbers: &[String],
+) -> String {
+    let data = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+        shipment_date,
+        transferor,
+        transferee,
+        tracking_number,
+        po_number,
+        invoice_number,
+        serial_numbers.join("\n")
+    );
+
+    let mut hasher = Sha256::new();
+    hasher.update(data.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Fluently composes a `TransferPayload`, computing the idempotency key
+/// automatically from the fields supplied so far when `build()` is called.
+#[derive(Debug, Default)]
+pub struct TransferBuilder {
+    schema: Option<String>,
+    shipment_date: Option<String>,
+    transferor: Option<String>,
+    transferee: Option<String>,
+    transferee_emails: Vec<String>,
+    tracking_number: Option<String>,
+    po_number: Option<String>,
+    invoice_number: Option<String>,
+    acquire_type: Option<String>,
+    note: Option<String>,
+    items: Vec<Item>,
+}
+
+impl TransferBuilder {
+    pub fn new() -> Self {
+        Self {
+            schema: Some("https://schemas.fastbound.org/transfers-push-v1.json".to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn shipment_date(mut self, shipment_date: impl Into<String>) -> Self {
+        self.shipment_date = Some(shipment_date.into());
+        self
+    }
+
+    pub fn transferor(mut self, transferor: impl Into<String>) -> Self {
+        self.transferor = Some(transferor.into());
+        self
+    }
+
+    pub fn transferee(mut self, transferee: impl Into<String>) -> Self {
+        self.transferee = Some(transferee.into());
+        self
+    }
+
+    pub fn transferee_email(mut self, email: impl Into<String>) -> Self {
+        self.transferee_emails.push(email.into());
+        self
+    }
+
+    pub fn tracking_number(mut self, tracking_number: impl Into<String>) -> Self {
+        self.tracking_number = Some(tracking_number.into());
+        self
+    }
+
+    pub fn po_number(mut self, po_number: impl Into<String>) -> Self {
+        self.po_number = Some(po_number.into());
+        self
+    }
+
+    pub fn invoice_number(mut self, invoice_number: impl Into<String>) -> Self {
+        self.invoice_number = Some(invoice_number.into());
+        self
+    }
+
+    pub fn acquire_type(mut self, acquire_type: impl Into<String>) -> Self {
+        self.acquire_type = Some(acquire_type.into());
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    pub fn item(mut self, item: Item) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Assembles the `TransferPayload`, deriving `idempotency_key` from the
+    /// shipment metadata and item serial numbers collected so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a required field (`transferor`, `transferee`,
+    /// `shipment_date`) was never set.
+    pub fn build(self) -> TransferPayload {
+        let shipment_date = self.shipment_date.expect("shipment_date is required");
+        let transferor = self.transferor.expect("transferor is required");
+        let transferee = self.transferee.expect("transferee is required");
+        let tracking_number = self.tracking_number.unwrap_or_default();
+        let po_number = self.po_number.unwrap_or_default();
+        let invoice_number = self.invoice_number.unwrap_or_default();
+
+        let serial_numbers: Vec<String> =
+            self.items.iter().map(|item| item.serial.clone()).collect();
+        let idempotency_key = generate_idempotency_key(
+            &shipment_date,
+            &transferor,
+            &transferee,
+            &tracking_number,
+            &po_number,
+            &invoice_number,
+            &serial_numbers,
+        );
+
+        TransferPayload {
+            schema: self
+                .schema
+                .unwrap_or_else(|| "https://schemas.fastbound.org/transfers-push-v1.json".to_string()),
+            idempotency_key,
+            shipment_date,
+            transferor,
+            transferee,
+            transferee_emails: self.transferee_emails,
+            tracking_number,
+            po_number,
+            invoice_number,
+            acquire_type: self.acquire_type.unwrap_or_default(),
+            note: self.note.unwrap_or_default(),
+            items: self.items,
+        }
+    }
+}
+
+// These tests pin the `#[serde(rename_all)]`/`#[serde(rename)]` attributes
+// to the keys we believe `transfers-push-v1.json` expects, so a future field
+// addition that forgets the casing convention fails loudly. We don't have a
+// copy of the schema in this repo to assert against directly, so they check
+// our conversion is internally consistent, not that it matches FastBound's
+// published schema.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_item() -> Item {
+        Item {
+            manufacturer: "Glock".to_string(),
+            importer: None,
+            country: "Austria".to_string(),
+            model: "G17".to_string(),
+            caliber: "9mm".to_string(),
+            item_type: "Pistol".to_string(),
+            serial: "ABC123456".to_string(),
+            sku: "GLK-G17".to_string(),
+            mpn: "G17MPN".to_string(),
+            upc: "123456789012".to_string(),
+            barrel_length: 4.48,
+            overall_length: 8.03,
+            cost: 500.00,
+            price: 650.00,
+            condition: "New".to_string(),
+            note: "Brand new firearm".to_string(),
+        }
+    }
+
+    #[test]
+    fn item_serializes_to_expected_keys() {
+        let value = serde_json::to_value(sample_item()).unwrap();
+        let object = value.as_object().unwrap();
+        assert!(object.contains_key("type"));
+        assert!(object.contains_key("barrelLength"));
+        assert!(object.contains_key("overallLength"));
+        assert!(!object.contains_key("barrel_length"));
+        assert!(!object.contains_key("item_type"));
+    }
+
+    #[test]
+    fn item_round_trips_through_json() {
+        let item = sample_item();
+        let json = serde_json::to_string(&item).unwrap();
+        let parsed: Item = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.serial, item.serial);
+        assert_eq!(parsed.barrel_length, item.barrel_length);
+    }
+
+    #[test]
+    fn transfer_payload_serializes_to_expected_keys() {
+        let payload = TransferBuilder::new()
+            .shipment_date("2024-01-01")
+            .transferor("1-23-456-78-9A-12345")
+            .transferee("1-23-456-78-9B-54321")
+            .po_number("PO123")
+            .invoice_number("INV123")
+            .item(sample_item())
+            .build();
+
+        let value = serde_json::to_value(&payload).unwrap();
+        let object = value.as_object().unwrap();
+        assert!(object.contains_key("$schema"));
+        assert!(object.contains_key("idempotencyKey"));
+        assert!(object.contains_key("shipmentDate"));
+        assert!(object.contains_key("transfereeEmails"));
+        assert!(object.contains_key("trackingNumber"));
+        assert!(object.contains_key("poNumber"));
+        assert!(object.contains_key("invoiceNumber"));
+        assert!(object.contains_key("acquireType"));
+        assert!(!object.contains_key("idempotency_key"));
+    }
+}
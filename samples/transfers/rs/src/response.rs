@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// FastBound's response body for a successfully accepted transfer.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferResponse {
+    pub id: String,
+    pub status: String,
+}
+
+/// A single field-level validation failure, as returned in a 422 response.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// FastBound's response body for a 422 validation failure.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferError {
+    pub message: String,
+    #[serde(default)]
+    pub errors: Vec<FieldError>,
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Controls how `FastBoundClient` retries a submission after a transient
+/// failure.
+///
+/// Because the idempotency key is derived from the payload itself, retries
+/// safely re-POST the exact same body: FastBound de-duplicates the transfer
+/// server-side rather than creating a second one.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries; the client makes exactly one attempt.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Delay before retry attempt `attempt` (0-indexed), using exponential
+    /// backoff with full jitter: a random value in `[0, base * 2^attempt]`,
+    /// capped at `max_delay`. When that bound is zero, it is raised to 1 ms,
+    /// so the delay is then 0 or 1 ms.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.as_millis().saturating_mul(1u128 << attempt.min(32));
+        let capped = exp.min(self.max_delay.as_millis());
+        let jittered = rand::thread_rng().gen_range(0..=capped.max(1));
+        Duration::from_millis(jittered as u64)
+    }
+}
+
+/// Whether an HTTP status code returned by FastBound should be retried.
+/// 4xx validation failures are never retried since resubmitting the same
+/// payload will fail the same way.
+pub fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(
+        status,
+        reqwest::StatusCode::TOO_MANY_REQUESTS
+            | reqwest::StatusCode::INTERNAL_SERVER_ERROR
+            | reqwest::StatusCode::BAD_GATEWAY
+            | reqwest::StatusCode::SERVICE_UNAVAILABLE
+            | reqwest::StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_stays_within_a_millisecond_at_a_zero_base_delay() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(0),
+            max_delay: Duration::from_secs(30),
+        };
+        assert!(policy.backoff(0) <= Duration::from_millis(1));
+        assert!(policy.backoff(5) <= Duration::from_millis(1));
+    }
+
+    #[test]
+    fn backoff_never_exceeds_the_exponential_bound() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        };
+        for attempt in 0..6 {
+            let bound = Duration::from_millis(500 * (1u64 << attempt));
+            for _ in 0..50 {
+                assert!(policy.backoff(attempt) <= bound);
+            }
+        }
+    }
+
+    #[test]
+    fn backoff_never_exceeds_max_delay() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(1),
+        };
+        for _ in 0..50 {
+            assert!(policy.backoff(20) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn none_policy_never_retries() {
+        assert_eq!(RetryPolicy::none().max_retries, 0);
+    }
+
+    #[test]
+    fn is_retryable_status_covers_429_and_5xx() {
+        assert!(is_retryable_status(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(reqwest::StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_retryable_status(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(is_retryable_status(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_retryable_status(reqwest::StatusCode::GATEWAY_TIMEOUT));
+    }
+
+    #[test]
+    fn is_retryable_status_excludes_4xx_validation_failures() {
+        assert!(!is_retryable_status(reqwest::StatusCode::BAD_REQUEST));
+        assert!(!is_retryable_status(reqwest::StatusCode::UNAUTHORIZED));
+        assert!(!is_retryable_status(reqwest::StatusCode::FORBIDDEN));
+        assert!(!is_retryable_status(reqwest::StatusCode::CONFLICT));
+        assert!(!is_retryable_status(reqwest::StatusCode::UNPROCESSABLE_ENTITY));
+    }
+}